@@ -617,10 +617,12 @@ impl CommandServer {
 
     if let &ProxyRequestData::Logging(ref logging_filter) = &order {
       debug!("Changing master log level to {}", logging_filter);
-      logging::LOGGER.with(|l| {
-        let directives = logging::parse_logging_spec(&logging_filter);
-        l.borrow_mut().set_directives(directives);
-      });
+      match logging::parse_logging_spec(&logging_filter) {
+        Ok(directives) => logging::with_logger(|logger| {
+          logger.set_directives(directives);
+        }),
+        Err(e) => error!("invalid logging filter: {}", e),
+      }
       // also change / set the content of RUST_LOG so future workers / main thread
       // will have the new logging filter value
       ::std::env::set_var("RUST_LOG", logging_filter);
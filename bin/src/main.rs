@@ -8,7 +8,6 @@ extern crate serde_json;
 extern crate time;
 extern crate libc;
 extern crate slab;
-extern crate rand;
 extern crate nix;
 extern crate tempfile;
 extern crate futures;
@@ -13,6 +13,14 @@ extern crate serde_json;
 extern crate mio_uds;
 extern crate libc;
 extern crate time;
+#[cfg(feature = "logging-tls")]
+extern crate rustls;
+#[cfg(feature = "logging-tls")]
+extern crate webpki;
+#[cfg(feature = "logging-tracing")]
+extern crate tracing;
+#[cfg(feature = "logging-compression")]
+extern crate flate2;
 
 #[macro_use] pub mod logging;
 pub mod certificate;
@@ -1,3 +1,4 @@
+use std::cmp;
 use std::io::{self, Error, ErrorKind, Write};
 
 pub struct MultiLineWriter<W: Write> {
@@ -35,7 +36,9 @@ impl<W: Write> MultiLineWriter<W> {
         let len = if flush_entire_buffer {
             self.buf.len()
         } else {
-            self.last_newline + 1
+            // the buffer can be empty if a line larger than its capacity
+            // is written directly to the inner writer
+            cmp::min(self.last_newline + 1, self.buf.len())
         };
 
         let mut ret = Ok(());
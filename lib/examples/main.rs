@@ -5,7 +5,6 @@ extern crate time;
 
 use std::thread;
 use std::env;
-use std::io::stdout;
 use sozu_command::logging::{Logger,LoggerBackend};
 use sozu_command::proxy;
 use sozu_command::proxy::LoadBalancingParams;
@@ -13,9 +12,9 @@ use sozu_command::channel::Channel;
 
 fn main() {
   if env::var("RUST_LOG").is_ok() {
-   Logger::init("EXAMPLE".to_string(), &env::var("RUST_LOG").expect("could not get the RUST_LOG env var"), LoggerBackend::Stdout(stdout()), None);
+   Logger::init("EXAMPLE".to_string(), &env::var("RUST_LOG").expect("could not get the RUST_LOG env var"), LoggerBackend::stdout(), None);
   } else {
-   Logger::init("EXAMPLE".to_string(), "info", LoggerBackend::Stdout(stdout()), None);
+   Logger::init("EXAMPLE".to_string(), "info", LoggerBackend::stdout(), None);
   }
 
   info!("MAIN\tstarting up");
@@ -4,7 +4,6 @@ extern crate sozu_lib as sozu;
 extern crate time;
 
 use std::thread;
-use std::io::stdout;
 use sozu_command::proxy::{self, TcpListener, LoadBalancingParams};
 use sozu_command::channel::Channel;
 use sozu_command::logging::{Logger,LoggerBackend};
@@ -12,12 +11,12 @@ use sozu_command::logging::{Logger,LoggerBackend};
 fn main() {
   /*
   if env::var("RUST_LOG").is_ok() {
-   Logger::init("EXAMPLE".to_string(), &env::var("RUST_LOG").expect("could not get the RUST_LOG env var"), LoggerBackend::Stdout(stdout()));
+   Logger::init("EXAMPLE".to_string(), &env::var("RUST_LOG").expect("could not get the RUST_LOG env var"), LoggerBackend::stdout());
   } else {
-   Logger::init("EXAMPLE".to_string(), "info", LoggerBackend::Stdout(stdout()));
+   Logger::init("EXAMPLE".to_string(), "info", LoggerBackend::stdout());
   }
   */
- Logger::init("EXAMPLE".to_string(), "debug", LoggerBackend::Stdout(stdout()), None);
+ Logger::init("EXAMPLE".to_string(), "debug", LoggerBackend::stdout(), None);
 
   info!("starting up");
 
@@ -32,7 +31,7 @@ fn main() {
       public_address: None,
       expect_proxy: false,
     };
-    Logger::init("TCP".to_string(), "debug", LoggerBackend::Stdout(stdout()), None);
+    Logger::init("TCP".to_string(), "debug", LoggerBackend::stdout(), None);
     sozu::tcp::start(listener, max_buffers, buffer_size, channel);
   });
 
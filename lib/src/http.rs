@@ -1208,11 +1208,18 @@ impl ProxyConfiguration<Session> for Proxy {
       },
       ProxyRequestData::Logging(logging_filter) => {
         info!("{} changing logging filter to {}", message.id, logging_filter);
-        logging::LOGGER.with(|l| {
-          let directives = logging::parse_logging_spec(&logging_filter);
-          l.borrow_mut().set_directives(directives);
-        });
-        ProxyResponse{ id: message.id, status: ProxyResponseStatus::Ok, data: None }
+        match logging::parse_logging_spec(&logging_filter) {
+          Ok(directives) => {
+            logging::with_logger(|logger| {
+              logger.set_directives(directives);
+            });
+            ProxyResponse{ id: message.id, status: ProxyResponseStatus::Ok, data: None }
+          },
+          Err(e) => {
+            error!("{} invalid logging filter: {}", message.id, e);
+            ProxyResponse{ id: message.id, status: ProxyResponseStatus::Error(format!("invalid logging filter: {}", e)), data: None }
+          },
+        }
       },
       command => {
         debug!("{} unsupported message for HTTP proxy, ignoring: {:?}", message.id, command);
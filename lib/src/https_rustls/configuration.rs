@@ -662,11 +662,18 @@ impl ProxyConfiguration<Session> for Proxy {
       },
       ProxyRequestData::Logging(logging_filter) => {
         debug!("{} changing logging filter to {}", message.id, logging_filter);
-        logging::LOGGER.with(|l| {
-          let directives = logging::parse_logging_spec(&logging_filter);
-          l.borrow_mut().set_directives(directives);
-        });
-        ProxyResponse{ id: message.id, status: ProxyResponseStatus::Ok, data: None }
+        match logging::parse_logging_spec(&logging_filter) {
+          Ok(directives) => {
+            logging::with_logger(|logger| {
+              logger.set_directives(directives);
+            });
+            ProxyResponse{ id: message.id, status: ProxyResponseStatus::Ok, data: None }
+          },
+          Err(e) => {
+            error!("{} invalid logging filter: {}", message.id, e);
+            ProxyResponse{ id: message.id, status: ProxyResponseStatus::Error(format!("invalid logging filter: {}", e)), data: None }
+          },
+        }
       },
       ProxyRequestData::Query(Query::Certificates(QueryCertificateType::All)) => {
         let res = self.listeners.iter().map(|(addr, listener)| {